@@ -4,18 +4,20 @@ use wallet_core::{
     get_wordlist,
     mnemonic_to_seed,
     seed_to_master_key,
-    derive_path,
+    derive_path,
     private_to_public_key,
     public_key_to_address,
     to_checksum_address,
     EthereumNetwork,
     get_balance,
     get_transaction_count,
-    get_gas_price,
+    is_eoa,
     send_raw_transaction,
+    suggest_fees,
+    wait_for_receipt,
     wei_to_eth,
     eth_to_wei,
-    create_signed_transaction,
+    create_signed_transaction_1559,
 };
 use std::io::{self, Write};
 
@@ -110,15 +112,28 @@ async fn send_transaction(network: &EthereumNetwork, private_key: &[u8; 32], fro
     io::stdout().flush().unwrap();
     let mut amount_str = String::new();
     io::stdin().read_line(&mut amount_str).unwrap();
-    let amount: f64 = match amount_str.trim().parse() {
-        Ok(a) => a,
-        Err(_) => {
-            println!("❌ Invalid amount");
+    let amount = amount_str.trim();
+
+    let value_wei = match eth_to_wei(amount) {
+        Ok(w) => w,
+        Err(e) => {
+            println!("❌ Invalid amount: {}", e);
             return;
         }
     };
 
-    let value_wei = eth_to_wei(amount);
+    // Refuse to send from a contract account (EIP-3607)
+    match is_eoa(network, from_address).await {
+        Ok(true) => {}
+        Ok(false) => {
+            println!("❌ sender address has code, cannot send as EOA");
+            return;
+        }
+        Err(e) => {
+            println!("❌ Error checking sender account: {}", e);
+            return;
+        }
+    }
 
     // Get nonce
     println!("\n⏳ Fetching transaction count...");
@@ -130,91 +145,104 @@ async fn send_transaction(network: &EthereumNetwork, private_key: &[u8; 32], fro
         }
     };
 
-    // Get gas price
-    println!("⏳ Fetching gas price...");
-    let gas_price_hex = match get_gas_price(network).await {
-        Ok(gp) => gp,
+    // Get EIP-1559 fees
+    println!("⏳ Fetching fee estimate...");
+    let (max_fee_per_gas, max_priority_fee_per_gas) = match suggest_fees(network).await {
+        Ok(fees) => fees,
         Err(e) => {
-            println!("❌ Error getting gas price: {}", e);
+            println!("❌ Error estimating fees: {}", e);
             return;
         }
     };
 
-    let gas_price = u128::from_str_radix(gas_price_hex.trim_start_matches("0x"), 16).unwrap();
     let gas_limit = 21000u64; // Standard ETH transfer
 
-    println!("\n📝 Transaction Details:");
-    println!("From:      {}", from_address);
-    println!("To:        {}", to_address);
-    println!("Amount:    {} ETH", amount);
-    println!("Gas Limit: {}", gas_limit);
-    println!("Gas Price: {} wei", gas_price);
-    println!("Nonce:     {}", nonce);
-    
-    print!("\nConfirm transaction? (yes/no): ");
-    io::stdout().flush().unwrap();
-    let mut confirm = String::new();
-    io::stdin().read_line(&mut confirm).unwrap();
-    
-    if confirm.trim().to_lowercase() != "yes" {
-        println!("❌ Transaction cancelled");
-        return;
-    }
-    
-    // Create and sign transaction
-    println!("\n⏳ Signing transaction...");
-    let signed_tx = match create_signed_transaction(
-        private_key,
-        to_address,
-        value_wei,
-        nonce,
-        gas_price,
-        gas_limit,
-        network.chain_id,
-    ) {
-        Ok(tx) => tx,
-        Err(e) => {
-            println!("❌ Error signing transaction: {}", e);
-            return;
-        }
-    };
-    
-    // Send transaction
-    println!("⏳ Broadcasting transaction...");
-    match send_raw_transaction(network, &signed_tx).await {
-        Ok(tx_hash) => {
-            println!("✅ Transaction sent!");
-            println!("Transaction Hash: {}", tx_hash);
-            println!("View on Sepolia Etherscan: https://sepolia.etherscan.io/tx/{}", tx_hash);
-        }
-        Err(e) => println!("❌ Error sending transaction: {}", e),
-    }
-}
-
-async fn get_account_info(network: &EthereumNetwork, address: &str) {
-    println!("\n📊 Account Information");
-    let separator = "=".repeat(70);
-    println!("{}", separator);
-    
-    // Get balance
-    print!("Balance: ");
-    match get_balance(network, address).await {
-        Ok(balance_hex) => {
-            match wei_to_eth(&balance_hex) {
-                Ok(eth_balance) => println!("{} ETH", eth_balance),
-                Err(_) => println!("{} wei", balance_hex),
-            }
-        }
-        Err(e) => println!("Error: {}", e),
-    }
-    
-    // Get nonce
-    print!("Transaction Count: ");
-    match get_transaction_count(network, address).await {
-        Ok(nonce) => println!("{}", nonce),
-        Err(e) => println!("Error: {}", e),
-    }
-    
-    println!("{}", separator);
-    println!("View on Sepolia Etherscan: https://sepolia.etherscan.io/address/{}", address);
+    println!("\n📝 Transaction Details:");
+    println!("From:              {}", from_address);
+    println!("To:                {}", to_address);
+    println!("Amount:            {} ETH", amount);
+    println!("Gas Limit:         {}", gas_limit);
+    println!("Max Fee:           {} wei", max_fee_per_gas);
+    println!("Max Priority Fee:  {} wei", max_priority_fee_per_gas);
+    println!("Nonce:             {}", nonce);
+
+    print!("\nConfirm transaction? (yes/no): ");
+    io::stdout().flush().unwrap();
+    let mut confirm = String::new();
+    io::stdin().read_line(&mut confirm).unwrap();
+
+    if confirm.trim().to_lowercase() != "yes" {
+        println!("❌ Transaction cancelled");
+        return;
+    }
+
+    // Create and sign transaction
+    println!("\n⏳ Signing transaction...");
+    let signed_tx = match create_signed_transaction_1559(
+        private_key,
+        to_address,
+        value_wei,
+        nonce,
+        max_priority_fee_per_gas,
+        max_fee_per_gas,
+        gas_limit,
+        network.chain_id,
+    ) {
+        Ok(tx) => tx,
+        Err(e) => {
+            println!("❌ Error signing transaction: {}", e);
+            return;
+        }
+    };
+
+    // Send transaction
+    println!("⏳ Broadcasting transaction...");
+    match send_raw_transaction(network, &signed_tx).await {
+        Ok(tx_hash) => {
+            println!("✅ Transaction sent!");
+            println!("Transaction Hash: {}", tx_hash);
+            println!("View on Sepolia Etherscan: https://sepolia.etherscan.io/tx/{}", tx_hash);
+
+            println!("\n⏳ Waiting for confirmation...");
+            match wait_for_receipt(network, &tx_hash, std::time::Duration::from_secs(120)).await {
+                Ok(receipt) if receipt.status => {
+                    println!("✅ Confirmed in block {}", receipt.block_number);
+                    println!("Gas Used: {}", receipt.gas_used);
+                }
+                Ok(receipt) => {
+                    println!("❌ Transaction failed (block {})", receipt.block_number);
+                }
+                Err(e) => println!("❌ Error waiting for receipt: {}", e),
+            }
+        }
+        Err(e) => println!("❌ Error sending transaction: {}", e),
+    }
+}
+
+async fn get_account_info(network: &EthereumNetwork, address: &str) {
+    println!("\n📊 Account Information");
+    let separator = "=".repeat(70);
+    println!("{}", separator);
+
+    // Get balance
+    print!("Balance: ");
+    match get_balance(network, address).await {
+        Ok(balance_hex) => {
+            match wei_to_eth(&balance_hex) {
+                Ok(eth_balance) => println!("{} ETH", eth_balance),
+                Err(_) => println!("{} wei", balance_hex),
+            }
+        }
+        Err(e) => println!("Error: {}", e),
+    }
+
+    // Get nonce
+    print!("Transaction Count: ");
+    match get_transaction_count(network, address).await {
+        Ok(nonce) => println!("{}", nonce),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    println!("{}", separator);
+    println!("View on Sepolia Etherscan: https://sepolia.etherscan.io/address/{}", address);
 }
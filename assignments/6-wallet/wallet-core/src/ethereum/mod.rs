@@ -1,4 +1,6 @@
 pub mod address;
+mod base58check;
+pub mod ecies;
 pub mod network;
 pub mod private_key;
 pub mod public_key;
@@ -6,12 +8,17 @@ pub mod transaction;
 
 pub use address::{display_address, public_key_to_address, to_checksum_address};
 pub use network::{
-    eth_to_wei, get_balance, get_gas_price, get_transaction_count, send_raw_transaction,
-    wei_to_eth, EthereumNetwork,
+    eth_to_wei, get_balance, get_gas_price, get_transaction, get_transaction_count, is_eoa,
+    send_raw_transaction, suggest_fees, wait_for_receipt, wei_to_eth, EthereumNetwork, Receipt,
+    RpcClient, Transaction,
+};
+pub use private_key::{
+    derive_path, derive_public_child, derive_public_path, display_private_key,
+    seed_to_master_key, ChildNumber, DerivationPath, ExtendedPrivateKey, ExtendedPublicKey,
+    VERSION_XPRV_MAINNET, VERSION_XPUB_MAINNET,
 };
-pub use private_key::{derive_path, display_private_key, seed_to_master_key, ExtendedPrivateKey};
 pub use public_key::{display_public_key, private_to_public_key};
-pub use transaction::create_signed_transaction;
+pub use transaction::{create_signed_transaction, create_signed_transaction_1559};
 
 /// Standard Ethereum derivation paths
 pub mod paths {
@@ -0,0 +1,35 @@
+use sha2::{Digest, Sha256};
+
+/// Append a 4-byte double-SHA256 checksum and Base58-encode the payload.
+pub(crate) fn encode_base58check(payload: &[u8]) -> String {
+    let checksum = double_sha256(payload);
+    let mut full = payload.to_vec();
+    full.extend_from_slice(&checksum[0..4]);
+    bs58::encode(full).into_string()
+}
+
+/// Base58-decode a string and verify (and strip) its 4-byte checksum.
+pub(crate) fn decode_base58check(s: &str) -> Result<Vec<u8>, String> {
+    let data = bs58::decode(s)
+        .into_vec()
+        .map_err(|e| format!("Invalid base58: {}", e))?;
+
+    if data.len() < 4 {
+        return Err("Base58Check payload too short".to_string());
+    }
+
+    let (payload, checksum) = data.split_at(data.len() - 4);
+    let expected = double_sha256(payload);
+
+    if checksum != &expected[0..4] {
+        return Err("Base58Check checksum mismatch".to_string());
+    }
+
+    Ok(payload.to_vec())
+}
+
+fn double_sha256(data: &[u8]) -> [u8; 32] {
+    let first = Sha256::digest(data);
+    let second = Sha256::digest(first);
+    second.into()
+}
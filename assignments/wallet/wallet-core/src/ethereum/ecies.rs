@@ -0,0 +1,188 @@
+use aes::Aes128;
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use secp256k1::{PublicKey, Scalar, Secp256k1, SecretKey};
+use sha2::{Digest, Sha256};
+
+type Aes128Ctr = ctr::Ctr128BE<Aes128>;
+type HmacSha256 = Hmac<Sha256>;
+
+const TAG_LEN: usize = 32;
+const IV_LEN: usize = 16;
+const EPHEMERAL_PUBKEY_LEN: usize = 65;
+
+/// Encrypt `plaintext` to `recipient_public_key` (64-byte uncompressed
+/// public key, as returned by `private_to_public_key`).
+///
+/// Wire format: `0x04 || ephemeral_pubkey(65) || iv(16) || ciphertext ||
+/// tag(32)`.
+pub fn encrypt(recipient_public_key: &[u8; 64], plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    let secp = Secp256k1::new();
+
+    let mut ephemeral_bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut ephemeral_bytes);
+    let ephemeral_secret =
+        SecretKey::from_slice(&ephemeral_bytes).map_err(|_| "Failed to generate ephemeral key")?;
+    let ephemeral_public = ephemeral_secret.public_key(&secp);
+
+    let recipient_point = uncompressed_public_key(recipient_public_key)?;
+    let shared_x = shared_secret_x(&secp, &recipient_point, &ephemeral_secret)?;
+
+    let kdf_output = concat_kdf(&shared_x, 32);
+    let (aes_key, mac_key) = kdf_output.split_at(16);
+
+    let mut iv = [0u8; IV_LEN];
+    rand::thread_rng().fill_bytes(&mut iv);
+
+    let mut ciphertext = plaintext.to_vec();
+    let mut cipher = Aes128Ctr::new(aes_key.into(), (&iv).into());
+    cipher.apply_keystream(&mut ciphertext);
+
+    let tag = compute_tag(mac_key, &iv, &ciphertext)?;
+
+    let mut message = Vec::with_capacity(1 + EPHEMERAL_PUBKEY_LEN + IV_LEN + ciphertext.len() + TAG_LEN);
+    message.push(0x04);
+    message.extend_from_slice(&ephemeral_public.serialize_uncompressed());
+    message.extend_from_slice(&iv);
+    message.extend_from_slice(&ciphertext);
+    message.extend_from_slice(&tag);
+
+    Ok(message)
+}
+
+/// Decrypt a message produced by `encrypt` using the recipient's private key.
+pub fn decrypt(private_key: &[u8; 32], message: &[u8]) -> Result<Vec<u8>, String> {
+    let header_len = 1 + EPHEMERAL_PUBKEY_LEN + IV_LEN;
+    if message.len() < header_len + TAG_LEN {
+        return Err("Message too short to be a valid ECIES payload".to_string());
+    }
+    if message[0] != 0x04 {
+        return Err("Unsupported ECIES message prefix".to_string());
+    }
+
+    let ephemeral_public_bytes = &message[1..1 + EPHEMERAL_PUBKEY_LEN];
+    let iv = &message[1 + EPHEMERAL_PUBKEY_LEN..header_len];
+    let ciphertext = &message[header_len..message.len() - TAG_LEN];
+    let tag = &message[message.len() - TAG_LEN..];
+
+    let secp = Secp256k1::new();
+    let ephemeral_public =
+        PublicKey::from_slice(ephemeral_public_bytes).map_err(|_| "Invalid ephemeral public key")?;
+    let secret_key = SecretKey::from_slice(private_key).map_err(|_| "Invalid private key")?;
+
+    let shared_x = shared_secret_x(&secp, &ephemeral_public, &secret_key)?;
+
+    let kdf_output = concat_kdf(&shared_x, 32);
+    let (aes_key, mac_key) = kdf_output.split_at(16);
+
+    let expected_tag = compute_tag(mac_key, iv, ciphertext)?;
+    if !constant_time_eq(&expected_tag, tag) {
+        return Err("MAC verification failed".to_string());
+    }
+
+    let mut plaintext = ciphertext.to_vec();
+    let iv: [u8; IV_LEN] = iv.try_into().map_err(|_| "Invalid IV length")?;
+    let mut cipher = Aes128Ctr::new(aes_key.into(), (&iv).into());
+    cipher.apply_keystream(&mut plaintext);
+
+    Ok(plaintext)
+}
+
+fn uncompressed_public_key(public_key: &[u8; 64]) -> Result<PublicKey, String> {
+    let mut uncompressed = [0u8; 65];
+    uncompressed[0] = 0x04;
+    uncompressed[1..].copy_from_slice(public_key);
+    PublicKey::from_slice(&uncompressed).map_err(|_| "Invalid public key".to_string())
+}
+
+/// X coordinate of `scalar * point`, the ECDH shared secret.
+fn shared_secret_x(
+    secp: &Secp256k1<secp256k1::All>,
+    point: &PublicKey,
+    scalar: &SecretKey,
+) -> Result<[u8; 32], String> {
+    let shared_point = point
+        .mul_tweak(secp, &Scalar::from(*scalar))
+        .map_err(|_| "Failed to compute ECDH shared secret")?;
+
+    let mut x = [0u8; 32];
+    x.copy_from_slice(&shared_point.serialize_uncompressed()[1..33]);
+    Ok(x)
+}
+
+fn compute_tag(mac_key: &[u8], iv: &[u8], ciphertext: &[u8]) -> Result<[u8; 32], String> {
+    let mut mac = HmacSha256::new_from_slice(mac_key).map_err(|e| format!("HMAC error: {}", e))?;
+    mac.update(iv);
+    mac.update(ciphertext);
+    Ok(mac.finalize().into_bytes().into())
+}
+
+/// NIST SP800-56 concat-KDF over SHA-256.
+fn concat_kdf(shared_secret: &[u8], out_len: usize) -> Vec<u8> {
+    let mut output = Vec::with_capacity(out_len);
+    let mut counter: u32 = 1;
+
+    while output.len() < out_len {
+        let mut hasher = Sha256::new();
+        hasher.update(counter.to_be_bytes());
+        hasher.update(shared_secret);
+        output.extend_from_slice(&hasher.finalize());
+        counter += 1;
+    }
+
+    output.truncate(out_len);
+    output
+}
+
+/// Constant-time byte slice comparison, to avoid leaking tag-match timing.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+    diff == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recipient_keypair() -> ([u8; 32], [u8; 64]) {
+        let secp = Secp256k1::new();
+        let secret_bytes = [0x11u8; 32];
+        let secret_key = SecretKey::from_slice(&secret_bytes).unwrap();
+        let public_key = secret_key.public_key(&secp).serialize_uncompressed();
+
+        let mut public = [0u8; 64];
+        public.copy_from_slice(&public_key[1..]);
+        (secret_bytes, public)
+    }
+
+    #[test]
+    fn round_trip() {
+        let (private_key, public_key) = recipient_keypair();
+        let plaintext = b"the quick brown fox";
+
+        let message = encrypt(&public_key, plaintext).unwrap();
+        let decrypted = decrypt(&private_key, &message).unwrap();
+
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn tampered_ciphertext_fails_mac() {
+        let (private_key, public_key) = recipient_keypair();
+        let mut message = encrypt(&public_key, b"the quick brown fox").unwrap();
+
+        let first_ciphertext_byte = 1 + EPHEMERAL_PUBKEY_LEN + IV_LEN;
+        message[first_ciphertext_byte] ^= 0xFF;
+
+        let err = decrypt(&private_key, &message).unwrap_err();
+        assert_eq!(err, "MAC verification failed");
+    }
+}
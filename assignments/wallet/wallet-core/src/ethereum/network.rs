@@ -1,246 +1,669 @@
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone)]
 pub struct EthereumNetwork {
     pub rpc_url: String,
     pub chain_id: u64,
     pub name: String,
+    pub rpc_client: Arc<RpcClient>,
 }
 
-impl EthereumNetwork {
-    /// Sepolia testnet with primary RPC
-    pub fn sepolia() -> Self {
-        Self {
-            rpc_url: "https://ethereum-sepolia-rpc.publicnode.com".to_string(),
-            chain_id: 11155111,
-            name: "Sepolia".to_string(),
-        }
-    }
-    
-    /// Get list of Sepolia RPC endpoints to try
-    pub fn sepolia_rpcs() -> Vec<String> {
-        vec![
-            "https://ethereum-sepolia-rpc.publicnode.com".to_string(),
-            "https://rpc.sepolia.org".to_string(),
-            "https://sepolia.gateway.tenderly.co".to_string(),
-            "https://ethereum-sepolia.blockpi.network/v1/rpc/public".to_string(),
-        ]
-    }
-    
-    /// Sepolia with custom API key
-    pub fn sepolia_with_key(api_key: &str) -> Self {
-        Self {
-            rpc_url: format!("https://eth-sepolia.g.alchemy.com/v2/{}", api_key),
-            chain_id: 11155111,
-            name: "Sepolia".to_string(),
-        }
-    }
-    
-    /// Ethereum mainnet
-    pub fn mainnet() -> Self {
-        Self {
-            rpc_url: "https://eth.llamarpc.com".to_string(),
-            chain_id: 1,
-            name: "Mainnet".to_string(),
-        }
-    }
-    
-    /// Custom RPC
-    pub fn custom(rpc_url: String, chain_id: u64, name: String) -> Self {
-        Self {
-            rpc_url,
-            chain_id,
-            name,
-        }
-    }
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-struct JsonRpcRequest {
-    jsonrpc: String,
-    method: String,
-    params: serde_json::Value,
-    id: u64,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-struct JsonRpcResponse {
-    jsonrpc: String,
-    id: u64,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    result: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<JsonRpcError>,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-struct JsonRpcError {
-    code: i64,
-    message: String,
-}
-
-/// Make RPC call with fallback support
-async fn make_rpc_call(
-    rpc_urls: &[String],
-    method: &str,
-    params: serde_json::Value,
-) -> Result<serde_json::Value, String> {
-    let client = reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(30))
-        .build()
-        .map_err(|e| format!("Failed to build client: {}", e))?;
-    
-    let mut last_error = String::new();
-    
-    for (i, rpc_url) in rpc_urls.iter().enumerate() {
-        let request = JsonRpcRequest {
-            jsonrpc: "2.0".to_string(),
-            method: method.to_string(),
-            params: params.clone(),
-            id: 1,
-        };
-        
-        match client
-            .post(rpc_url)
-            .header("Content-Type", "application/json")
-            .json(&request)
-            .send()
-            .await
-        {
-            Ok(response) => {
-                let status = response.status();
-                
-                if status.as_u16() == 429 {
-                    last_error = format!("RPC {} rate limited", i + 1);
-                    continue;
-                }
-                
-                match response.text().await {
-                    Ok(body_text) => {
-                        if !status.is_success() {
-                            last_error = format!("HTTP error {}: {}", status, body_text);
-                            continue;
-                        }
-                        
-                        match serde_json::from_str::<JsonRpcResponse>(&body_text) {
-                            Ok(rpc_response) => {
-                                if let Some(error) = rpc_response.error {
-                                    last_error = format!("RPC error: {}", error.message);
-                                    continue;
-                                }
-                                
-                                if let Some(result) = rpc_response.result {
-                                    return Ok(result);
-                                }
-                                
-                                last_error = "No result in response".to_string();
-                            }
-                            Err(e) => {
-                                last_error = format!("Failed to parse JSON: {}", e);
-                                continue;
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        last_error = format!("Failed to read response: {}", e);
-                        continue;
-                    }
-                }
-            }
-            Err(e) => {
-                last_error = format!("Request to RPC {} failed: {}", i + 1, e);
-                continue;
-            }
-        }
-    }
-    
-    Err(format!("All RPCs failed. Last error: {}", last_error))
-}
-
-/// Get balance of an Ethereum address
-pub async fn get_balance(network: &EthereumNetwork, address: &str) -> Result<String, String> {
-    let rpcs = if network.chain_id == 11155111 {
-        EthereumNetwork::sepolia_rpcs()
-    } else {
-        vec![network.rpc_url.clone()]
-    };
-    
-    let result = make_rpc_call(&rpcs, "eth_getBalance", json!([address, "latest"])).await?;
-    
-    result
-        .as_str()
-        .map(String::from)
-        .ok_or_else(|| "Invalid balance format".to_string())
-}
-
-/// Get transaction count (nonce) for an address
-pub async fn get_transaction_count(network: &EthereumNetwork, address: &str) -> Result<u64, String> {
-    let rpcs = if network.chain_id == 11155111 {
-        EthereumNetwork::sepolia_rpcs()
-    } else {
-        vec![network.rpc_url.clone()]
-    };
-    
-    let result = make_rpc_call(&rpcs, "eth_getTransactionCount", json!([address, "latest"])).await?;
-    
-    let nonce_hex = result
-        .as_str()
-        .ok_or("Invalid nonce format")?;
-    
-    u64::from_str_radix(nonce_hex.trim_start_matches("0x"), 16)
-        .map_err(|e| format!("Failed to parse nonce: {}", e))
-}
-
-/// Get current gas price
-pub async fn get_gas_price(network: &EthereumNetwork) -> Result<String, String> {
-    let rpcs = if network.chain_id == 11155111 {
-        EthereumNetwork::sepolia_rpcs()
-    } else {
-        vec![network.rpc_url.clone()]
-    };
-    
-    let result = make_rpc_call(&rpcs, "eth_gasPrice", json!([])).await?;
-    
-    result
-        .as_str()
-        .map(String::from)
-        .ok_or_else(|| "Invalid gas price format".to_string())
-}
-
-/// Send raw transaction
-pub async fn send_raw_transaction(network: &EthereumNetwork, signed_tx: &str) -> Result<String, String> {
-    let rpcs = if network.chain_id == 11155111 {
-        EthereumNetwork::sepolia_rpcs()
-    } else {
-        vec![network.rpc_url.clone()]
-    };
-    
-    let result = make_rpc_call(&rpcs, "eth_sendRawTransaction", json!([signed_tx])).await?;
-    
-    result
-        .as_str()
-        .map(String::from)
-        .ok_or_else(|| "Invalid transaction hash format".to_string())
-}
-
-/// Convert hex balance to ETH (with decimals)
-pub fn wei_to_eth(wei_hex: &str) -> Result<String, String> {
-    let wei_hex = wei_hex.trim_start_matches("0x");
-    
-    if wei_hex.is_empty() || wei_hex == "0" {
-        return Ok("0.0".to_string());
-    }
-    
-    let wei = u128::from_str_radix(wei_hex, 16)
-        .map_err(|e| format!("Failed to parse wei: {}", e))?;
-    
-    let eth = wei as f64 / 1_000_000_000_000_000_000.0;
-    
-    Ok(format!("{:.18}", eth).trim_end_matches('0').trim_end_matches('.').to_string())
-}
-
-/// Convert ETH to wei
-pub fn eth_to_wei(eth: f64) -> u128 {
-    (eth * 1_000_000_000_000_000_000.0) as u128
+impl EthereumNetwork {
+    /// Sepolia testnet with primary RPC
+    pub fn sepolia() -> Self {
+        Self {
+            rpc_url: "https://ethereum-sepolia-rpc.publicnode.com".to_string(),
+            chain_id: 11155111,
+            name: "Sepolia".to_string(),
+            rpc_client: Arc::new(RpcClient::new(Self::sepolia_rpcs())),
+        }
+    }
+
+    /// Get list of Sepolia RPC endpoints to try
+    pub fn sepolia_rpcs() -> Vec<String> {
+        vec![
+            "https://ethereum-sepolia-rpc.publicnode.com".to_string(),
+            "https://rpc.sepolia.org".to_string(),
+            "https://sepolia.gateway.tenderly.co".to_string(),
+            "https://ethereum-sepolia.blockpi.network/v1/rpc/public".to_string(),
+        ]
+    }
+
+    /// Sepolia with custom API key
+    pub fn sepolia_with_key(api_key: &str) -> Self {
+        let rpc_url = format!("https://eth-sepolia.g.alchemy.com/v2/{}", api_key);
+        Self {
+            rpc_client: Arc::new(RpcClient::new(vec![rpc_url.clone()])),
+            rpc_url,
+            chain_id: 11155111,
+            name: "Sepolia".to_string(),
+        }
+    }
+
+    /// Ethereum mainnet
+    pub fn mainnet() -> Self {
+        let rpc_url = "https://eth.llamarpc.com".to_string();
+        Self {
+            rpc_client: Arc::new(RpcClient::new(vec![rpc_url.clone()])),
+            rpc_url,
+            chain_id: 1,
+            name: "Mainnet".to_string(),
+        }
+    }
+
+    /// Custom RPC
+    pub fn custom(rpc_url: String, chain_id: u64, name: String) -> Self {
+        Self {
+            rpc_client: Arc::new(RpcClient::new(vec![rpc_url.clone()])),
+            rpc_url,
+            chain_id,
+            name,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JsonRpcRequest {
+    jsonrpc: String,
+    method: String,
+    params: serde_json::Value,
+    id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JsonRpcResponse {
+    jsonrpc: String,
+    id: u64,
+    // A successful response always carries this field, even when its value
+    // is JSON `null` (e.g. `eth_getTransactionReceipt` on a pending tx).
+    // `serde`'s default `Option<Value>` deserialization maps `null` to
+    // `None`, which would read a `null` result as a missing one — so this is
+    // plain `Value`, not `Option<Value>`. With `#[serde(default)]` an absent
+    // field also reads as `Value::Null`; the two cases are not told apart.
+    #[serde(default)]
+    result: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<JsonRpcError>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String,
+}
+
+/// Health tracking for a single RPC endpoint.
+#[derive(Debug, Clone)]
+struct EndpointHealth {
+    url: String,
+    consecutive_failures: u32,
+    rate_limited_until: Option<Instant>,
+    avg_latency_ms: f64,
+}
+
+impl EndpointHealth {
+    fn new(url: String) -> Self {
+        Self {
+            url,
+            consecutive_failures: 0,
+            rate_limited_until: None,
+            avg_latency_ms: 0.0,
+        }
+    }
+
+    fn is_backing_off(&self, now: Instant) -> bool {
+        self.rate_limited_until.map_or(false, |until| now < until)
+    }
+}
+
+/// Multi-endpoint RPC client with health tracking, adaptive ordering, and
+/// exponential backoff on rate limiting.
+///
+/// Endpoints are reordered on every call to prefer the healthiest/fastest
+/// one first, so a slow or rate-limited endpoint stops being retried first
+/// on every request. Replaces the per-function `if chain_id == 11155111`
+/// endpoint list duplication with a single shared client.
+#[derive(Debug)]
+pub struct RpcClient {
+    endpoints: Mutex<Vec<EndpointHealth>>,
+    timeout: Duration,
+    max_retries: usize,
+}
+
+impl RpcClient {
+    pub fn new(urls: Vec<String>) -> Self {
+        Self {
+            endpoints: Mutex::new(urls.into_iter().map(EndpointHealth::new).collect()),
+            timeout: Duration::from_secs(30),
+            max_retries: 4,
+        }
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Endpoint indices ordered best-first: not backing off, fewest
+    /// consecutive failures, lowest average latency.
+    fn ordered_endpoint_indices(&self) -> Vec<usize> {
+        let endpoints = self.endpoints.lock().unwrap();
+        let now = Instant::now();
+
+        let mut indices: Vec<usize> = (0..endpoints.len()).collect();
+        indices.sort_by(|&a, &b| {
+            let ea = &endpoints[a];
+            let eb = &endpoints[b];
+            ea.is_backing_off(now)
+                .cmp(&eb.is_backing_off(now))
+                .then(ea.consecutive_failures.cmp(&eb.consecutive_failures))
+                .then(
+                    ea.avg_latency_ms
+                        .partial_cmp(&eb.avg_latency_ms)
+                        .unwrap_or(std::cmp::Ordering::Equal),
+                )
+        });
+        indices
+    }
+
+    fn record_success(&self, index: usize, latency: Duration) {
+        let mut endpoints = self.endpoints.lock().unwrap();
+        let endpoint = &mut endpoints[index];
+        endpoint.consecutive_failures = 0;
+        endpoint.rate_limited_until = None;
+
+        let latency_ms = latency.as_secs_f64() * 1000.0;
+        endpoint.avg_latency_ms = if endpoint.avg_latency_ms == 0.0 {
+            latency_ms
+        } else {
+            endpoint.avg_latency_ms * 0.7 + latency_ms * 0.3
+        };
+    }
+
+    fn record_failure(&self, index: usize, rate_limited: bool) {
+        let mut endpoints = self.endpoints.lock().unwrap();
+        let endpoint = &mut endpoints[index];
+        endpoint.consecutive_failures += 1;
+
+        if rate_limited {
+            let backoff = Duration::from_secs(2u64.saturating_pow(endpoint.consecutive_failures.min(6)));
+            endpoint.rate_limited_until = Some(Instant::now() + backoff);
+        }
+    }
+
+    /// Make an RPC call, trying endpoints best-first up to `max_retries`.
+    pub async fn call(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value, String> {
+        let client = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .build()
+            .map_err(|e| format!("Failed to build client: {}", e))?;
+
+        let order = self.ordered_endpoint_indices();
+
+        // Endpoints still inside their rate-limit backoff window are
+        // skipped in favor of ones that aren't, so backoff actually
+        // suppresses requests instead of just being tried last. Only fall
+        // back to a backing-off endpoint when every endpoint is one.
+        let now = Instant::now();
+        let (ready, backing_off): (Vec<usize>, Vec<usize>) = {
+            let endpoints = self.endpoints.lock().unwrap();
+            order.into_iter().partition(|&i| !endpoints[i].is_backing_off(now))
+        };
+        let candidates = if ready.is_empty() { backing_off } else { ready };
+        let attempts = candidates.len().min(self.max_retries.max(1));
+
+        let mut last_error = String::new();
+
+        for &index in candidates.iter().take(attempts) {
+            let url = self.endpoints.lock().unwrap()[index].url.clone();
+
+            let request = JsonRpcRequest {
+                jsonrpc: "2.0".to_string(),
+                method: method.to_string(),
+                params: params.clone(),
+                id: 1,
+            };
+
+            let started = Instant::now();
+            match client
+                .post(&url)
+                .header("Content-Type", "application/json")
+                .json(&request)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    let status = response.status();
+
+                    if status.as_u16() == 429 {
+                        self.record_failure(index, true);
+                        last_error = format!("RPC {} rate limited", url);
+                        continue;
+                    }
+
+                    match response.text().await {
+                        Ok(body_text) => {
+                            if !status.is_success() {
+                                self.record_failure(index, false);
+                                last_error = format!("HTTP error {}: {}", status, body_text);
+                                continue;
+                            }
+
+                            match serde_json::from_str::<JsonRpcResponse>(&body_text) {
+                                Ok(rpc_response) => {
+                                    if let Some(error) = rpc_response.error {
+                                        self.record_failure(index, false);
+                                        last_error = format!("RPC error: {}", error.message);
+                                        continue;
+                                    }
+
+                                    // `result` may legitimately be `null` (e.g. a pending
+                                    // tx's receipt) — that's still a successful response.
+                                    self.record_success(index, started.elapsed());
+                                    return Ok(rpc_response.result);
+                                }
+                                Err(e) => {
+                                    self.record_failure(index, false);
+                                    last_error = format!("Failed to parse JSON: {}", e);
+                                    continue;
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            self.record_failure(index, false);
+                            last_error = format!("Failed to read response: {}", e);
+                            continue;
+                        }
+                    }
+                }
+                Err(e) => {
+                    self.record_failure(index, false);
+                    last_error = format!("Request to {} failed: {}", url, e);
+                    continue;
+                }
+            }
+        }
+
+        Err(format!("All RPCs failed. Last error: {}", last_error))
+    }
+}
+
+/// Get balance of an Ethereum address
+pub async fn get_balance(network: &EthereumNetwork, address: &str) -> Result<String, String> {
+    let result = network
+        .rpc_client
+        .call("eth_getBalance", json!([address, "latest"]))
+        .await?;
+
+    result
+        .as_str()
+        .map(String::from)
+        .ok_or_else(|| "Invalid balance format".to_string())
+}
+
+/// Get transaction count (nonce) for an address
+pub async fn get_transaction_count(network: &EthereumNetwork, address: &str) -> Result<u64, String> {
+    let result = network
+        .rpc_client
+        .call("eth_getTransactionCount", json!([address, "latest"]))
+        .await?;
+
+    let nonce_hex = result.as_str().ok_or("Invalid nonce format")?;
+
+    u64::from_str_radix(nonce_hex.trim_start_matches("0x"), 16)
+        .map_err(|e| format!("Failed to parse nonce: {}", e))
+}
+
+/// Get current gas price
+pub async fn get_gas_price(network: &EthereumNetwork) -> Result<String, String> {
+    let result = network.rpc_client.call("eth_gasPrice", json!([])).await?;
+
+    result
+        .as_str()
+        .map(String::from)
+        .ok_or_else(|| "Invalid gas price format".to_string())
+}
+
+/// Suggest EIP-1559 fees for the given network.
+///
+/// Fetches the latest block, reads `baseFeePerGas`, and sizes
+/// `max_fee_per_gas = base_fee * 2 + priority_fee` so the transaction stays
+/// includable across a couple of base fee increases. The priority fee is
+/// read from `eth_maxPriorityFeePerGas` when the node supports it, falling
+/// back to a flat 1.5 gwei. Pre-London chains (no `baseFeePerGas`) fall back
+/// to the legacy `eth_gasPrice` value for both fields.
+pub async fn suggest_fees(network: &EthereumNetwork) -> Result<(u128, u128), String> {
+    const DEFAULT_PRIORITY_FEE_WEI: u128 = 1_500_000_000; // 1.5 gwei
+
+    let block = network
+        .rpc_client
+        .call("eth_getBlockByNumber", json!(["latest", false]))
+        .await?;
+
+    let base_fee_hex = block.get("baseFeePerGas").and_then(|v| v.as_str());
+
+    let base_fee = match base_fee_hex {
+        Some(hex_str) => u128::from_str_radix(hex_str.trim_start_matches("0x"), 16)
+            .map_err(|e| format!("Failed to parse baseFeePerGas: {}", e))?,
+        None => {
+            let gas_price_hex = get_gas_price(network).await?;
+            let gas_price = u128::from_str_radix(gas_price_hex.trim_start_matches("0x"), 16)
+                .map_err(|e| format!("Failed to parse gas price: {}", e))?;
+            return Ok((gas_price, gas_price));
+        }
+    };
+
+    let priority_fee = match network
+        .rpc_client
+        .call("eth_maxPriorityFeePerGas", json!([]))
+        .await
+    {
+        Ok(result) => result
+            .as_str()
+            .and_then(|s| u128::from_str_radix(s.trim_start_matches("0x"), 16).ok())
+            .unwrap_or(DEFAULT_PRIORITY_FEE_WEI),
+        Err(_) => DEFAULT_PRIORITY_FEE_WEI,
+    };
+
+    let max_fee_per_gas = base_fee * 2 + priority_fee;
+
+    Ok((max_fee_per_gas, priority_fee))
+}
+
+/// Check whether an address is a plain externally-owned account (no code).
+///
+/// Per EIP-3607, accounts with deployed code must not originate
+/// transactions; attempting to send from one wastes fees when the node
+/// rejects the broadcast. Callers should check this before signing.
+pub async fn is_eoa(network: &EthereumNetwork, address: &str) -> Result<bool, String> {
+    let result = network
+        .rpc_client
+        .call("eth_getCode", json!([address, "latest"]))
+        .await?;
+
+    let code = result
+        .as_str()
+        .ok_or_else(|| "Invalid code format".to_string())?;
+
+    Ok(code == "0x" || code.is_empty())
+}
+
+/// A mined transaction receipt.
+#[derive(Debug, Clone)]
+pub struct Receipt {
+    pub status: bool,
+    pub block_number: u64,
+    pub gas_used: u128,
+    pub effective_gas_price: u128,
+}
+
+/// A transaction as returned by `eth_getTransactionByHash`.
+#[derive(Debug, Clone)]
+pub struct Transaction {
+    pub hash: String,
+    pub transaction_type: u64,
+    pub block_number: Option<u64>,
+}
+
+fn parse_hex_u64(value: &serde_json::Value, field: &str) -> Result<u64, String> {
+    let hex_str = value
+        .as_str()
+        .ok_or_else(|| format!("Missing or invalid `{}` field", field))?;
+    u64::from_str_radix(hex_str.trim_start_matches("0x"), 16)
+        .map_err(|e| format!("Failed to parse `{}`: {}", field, e))
+}
+
+fn parse_hex_u128(value: &serde_json::Value, field: &str) -> Result<u128, String> {
+    let hex_str = value
+        .as_str()
+        .ok_or_else(|| format!("Missing or invalid `{}` field", field))?;
+    u128::from_str_radix(hex_str.trim_start_matches("0x"), 16)
+        .map_err(|e| format!("Failed to parse `{}`: {}", field, e))
+}
+
+/// Poll `eth_getTransactionReceipt` until the transaction is mined or the
+/// timeout elapses.
+pub async fn wait_for_receipt(
+    network: &EthereumNetwork,
+    tx_hash: &str,
+    timeout: Duration,
+) -> Result<Receipt, String> {
+    let poll_interval = Duration::from_secs(2);
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        let result = network
+            .rpc_client
+            .call("eth_getTransactionReceipt", json!([tx_hash]))
+            .await?;
+
+        if !result.is_null() {
+            let status_hex = result
+                .get("status")
+                .ok_or("Receipt missing `status` field")?;
+            let status = parse_hex_u64(status_hex, "status")? == 1;
+
+            let block_number = parse_hex_u64(
+                result.get("blockNumber").ok_or("Receipt missing `blockNumber`")?,
+                "blockNumber",
+            )?;
+            let gas_used = parse_hex_u128(
+                result.get("gasUsed").ok_or("Receipt missing `gasUsed`")?,
+                "gasUsed",
+            )?;
+            let effective_gas_price = result
+                .get("effectiveGasPrice")
+                .map(|v| parse_hex_u128(v, "effectiveGasPrice"))
+                .transpose()?
+                .unwrap_or(0);
+
+            return Ok(Receipt {
+                status,
+                block_number,
+                gas_used,
+                effective_gas_price,
+            });
+        }
+
+        if Instant::now() >= deadline {
+            return Err(format!(
+                "Timed out waiting for receipt of transaction {}",
+                tx_hash
+            ));
+        }
+
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+/// Fetch a transaction by hash, reporting its type (`0x0` legacy, `0x2`
+/// EIP-1559).
+pub async fn get_transaction(
+    network: &EthereumNetwork,
+    tx_hash: &str,
+) -> Result<Transaction, String> {
+    let result = network
+        .rpc_client
+        .call("eth_getTransactionByHash", json!([tx_hash]))
+        .await?;
+
+    if result.is_null() {
+        return Err(format!("Transaction {} not found", tx_hash));
+    }
+
+    let transaction_type = match result.get("type") {
+        Some(v) => parse_hex_u64(v, "type")?,
+        None => 0,
+    };
+
+    let block_number = match result.get("blockNumber") {
+        Some(v) if !v.is_null() => Some(parse_hex_u64(v, "blockNumber")?),
+        _ => None,
+    };
+
+    Ok(Transaction {
+        hash: tx_hash.to_string(),
+        transaction_type,
+        block_number,
+    })
+}
+
+/// Send raw transaction
+pub async fn send_raw_transaction(network: &EthereumNetwork, signed_tx: &str) -> Result<String, String> {
+    let result = network
+        .rpc_client
+        .call("eth_sendRawTransaction", json!([signed_tx]))
+        .await?;
+
+    result
+        .as_str()
+        .map(String::from)
+        .ok_or_else(|| "Invalid transaction hash format".to_string())
+}
+
+const WEI_PER_ETH: u128 = 1_000_000_000_000_000_000;
+
+/// Convert hex balance to ETH (with decimals)
+///
+/// Uses exact fixed-point division over `u128` wei instead of routing
+/// through `f64`, which silently loses precision above ~2^53.
+pub fn wei_to_eth(wei_hex: &str) -> Result<String, String> {
+    let wei_hex = wei_hex.trim_start_matches("0x");
+
+    if wei_hex.is_empty() || wei_hex == "0" {
+        return Ok("0.0".to_string());
+    }
+
+    let wei = u128::from_str_radix(wei_hex, 16)
+        .map_err(|e| format!("Failed to parse wei: {}", e))?;
+
+    let whole = wei / WEI_PER_ETH;
+    let frac = wei % WEI_PER_ETH;
+
+    let frac_str = format!("{:018}", frac);
+    let frac_trimmed = frac_str.trim_end_matches('0');
+
+    if frac_trimmed.is_empty() {
+        Ok(format!("{}.0", whole))
+    } else {
+        Ok(format!("{}.{}", whole, frac_trimmed))
+    }
+}
+
+/// Convert a decimal ETH string to wei.
+///
+/// Accepts at most 18 fractional digits (wei is the smallest unit) and
+/// returns an error on overflow or malformed input, rather than silently
+/// truncating like an `f64` round-trip would.
+pub fn eth_to_wei(eth: &str) -> Result<u128, String> {
+    let eth = eth.trim();
+
+    let (whole_str, frac_str) = match eth.split_once('.') {
+        Some((w, f)) => (w, f),
+        None => (eth, ""),
+    };
+
+    if frac_str.len() > 18 {
+        return Err(format!(
+            "Too many fractional digits: {} (max 18)",
+            frac_str.len()
+        ));
+    }
+
+    if (whole_str.is_empty() && frac_str.is_empty())
+        || !frac_str.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(format!("Invalid ETH amount: {}", eth));
+    }
+
+    let whole_str = if whole_str.is_empty() { "0" } else { whole_str };
+    let whole: u128 = whole_str
+        .parse()
+        .map_err(|_| format!("Invalid ETH amount: {}", eth))?;
+
+    let padded_frac = format!("{:0<18}", frac_str);
+    let frac: u128 = if padded_frac.is_empty() {
+        0
+    } else {
+        padded_frac
+            .parse()
+            .map_err(|_| format!("Invalid ETH amount: {}", eth))?
+    };
+
+    whole
+        .checked_mul(WEI_PER_ETH)
+        .and_then(|w| w.checked_add(frac))
+        .ok_or_else(|| "ETH amount overflows wei range".to_string())
+}
+
+#[cfg(test)]
+mod wei_eth_tests {
+    use super::*;
+
+    #[test]
+    fn eth_to_wei_basic() {
+        assert_eq!(eth_to_wei("0.5").unwrap(), 500_000_000_000_000_000);
+    }
+
+    #[test]
+    fn eth_to_wei_leading_dot() {
+        assert_eq!(eth_to_wei(".5").unwrap(), 500_000_000_000_000_000);
+    }
+
+    #[test]
+    fn eth_to_wei_trailing_dot() {
+        assert_eq!(eth_to_wei("1.").unwrap(), WEI_PER_ETH);
+    }
+
+    #[test]
+    fn eth_to_wei_rejects_too_many_frac_digits() {
+        assert!(eth_to_wei("1.1234567890123456789").is_err());
+    }
+
+    #[test]
+    fn eth_to_wei_rejects_overflow() {
+        assert!(eth_to_wei("1000000000000000000000000000000000000000").is_err());
+    }
+
+    #[test]
+    fn wei_to_eth_whole_value() {
+        assert_eq!(wei_to_eth("de0b6b3a7640000").unwrap(), "1.0");
+    }
+
+    #[test]
+    fn wei_to_eth_zero() {
+        assert_eq!(wei_to_eth("0x0").unwrap(), "0.0");
+    }
+
+    #[test]
+    fn round_trip_whole_eth() {
+        let wei = eth_to_wei("1.0").unwrap();
+        assert_eq!(wei_to_eth(&format!("{:x}", wei)).unwrap(), "1.0");
+    }
+
+    #[test]
+    fn round_trip_sub_gwei() {
+        // 1 wei is far below gwei (1e9 wei) granularity.
+        let wei = eth_to_wei("0.000000000000000001").unwrap();
+        assert_eq!(wei, 1);
+        assert_eq!(wei_to_eth(&format!("{:x}", wei)).unwrap(), "0.000000000000000001");
+    }
+
+    #[test]
+    fn round_trip_fractional_eth() {
+        let wei = eth_to_wei("3.14159").unwrap();
+        assert_eq!(wei_to_eth(&format!("{:x}", wei)).unwrap(), "3.14159");
+    }
 }
@@ -1,11 +1,19 @@
+use super::base58check::{decode_base58check, encode_base58check};
 use hmac::{Hmac, Mac};
-use secp256k1::{Secp256k1, SecretKey};
+use secp256k1::{PublicKey, Scalar, Secp256k1, SecretKey};
 use sha2::Sha512;
+use std::fmt;
+use std::str::FromStr;
 
 type HmacSha512 = Hmac<Sha512>;
 
 const HARDENED_OFFSET: u32 = 0x80000000; // 2^31
 
+/// Mainnet BIP32 version bytes for `xprv`
+pub const VERSION_XPRV_MAINNET: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4];
+/// Mainnet BIP32 version bytes for `xpub`
+pub const VERSION_XPUB_MAINNET: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+
 /// Extended Private Key structure for Ethereum
 #[derive(Debug, Clone)]
 pub struct ExtendedPrivateKey {
@@ -91,36 +99,328 @@ pub fn derive_private_child(
     })
 }
 
-/// Derive a key using a full derivation path
-/// Ethereum standard: m/44'/60'/0'/0/0
-pub fn derive_path(master: &ExtendedPrivateKey, path: &str) -> Result<ExtendedPrivateKey, String> {
-    // Remove "m/" or "m" prefix
-    let path = path.trim_start_matches("m/").trim_start_matches("m");
+/// A single BIP32 derivation step, either a normal or hardened child index.
+///
+/// The `normal` and `hardened` constructors (and `FromStr`) reject indices
+/// >= 2^31, since that range is reserved for signaling hardened derivation
+/// via the raw `u32` child number. Building a variant directly skips that
+/// check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChildNumber {
+    Normal { index: u32 },
+    Hardened { index: u32 },
+}
 
-    if path.is_empty() {
-        return Ok(master.clone());
+impl ChildNumber {
+    pub fn normal(index: u32) -> Result<Self, String> {
+        if index >= HARDENED_OFFSET {
+            return Err(format!(
+                "Normal child index must be < 2^31, got {}",
+                index
+            ));
+        }
+        Ok(ChildNumber::Normal { index })
     }
 
-    let mut current = master.clone();
+    pub fn hardened(index: u32) -> Result<Self, String> {
+        if index >= HARDENED_OFFSET {
+            return Err(format!(
+                "Hardened child index must be < 2^31, got {}",
+                index
+            ));
+        }
+        Ok(ChildNumber::Hardened { index })
+    }
 
-    for segment in path.split('/') {
-        let (index_str, hardened) = if segment.ends_with('\'') || segment.ends_with('h') {
-            (&segment[..segment.len() - 1], true)
-        } else {
-            (segment, false)
+    /// The raw BIP32 child number, with the hardened offset folded in.
+    fn to_raw(self) -> u32 {
+        match self {
+            ChildNumber::Normal { index } => index,
+            ChildNumber::Hardened { index } => HARDENED_OFFSET + index,
+        }
+    }
+}
+
+impl FromStr for ChildNumber {
+    type Err = String;
+
+    fn from_str(segment: &str) -> Result<Self, Self::Err> {
+        let (index_str, hardened) = match segment.strip_suffix('\'').or_else(|| segment.strip_suffix('h')) {
+            Some(stripped) => (stripped, true),
+            None => (segment, false),
         };
 
         let index: u32 = index_str
             .parse()
             .map_err(|_| format!("Invalid path segment: {}", segment))?;
 
-        let child_number = if hardened {
-            HARDENED_OFFSET + index
+        if hardened {
+            ChildNumber::hardened(index)
         } else {
-            index
+            ChildNumber::normal(index)
+        }
+    }
+}
+
+impl fmt::Display for ChildNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChildNumber::Normal { index } => write!(f, "{}", index),
+            ChildNumber::Hardened { index } => write!(f, "{}'", index),
+        }
+    }
+}
+
+/// A full BIP32 derivation path, e.g. `m/44'/60'/0'/0/0`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DerivationPath(Vec<ChildNumber>);
+
+impl DerivationPath {
+    pub fn new(children: Vec<ChildNumber>) -> Self {
+        Self(children)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ChildNumber> {
+        self.0.iter()
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = String;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        // Remove "m/" or "m" prefix
+        let path = path.trim_start_matches("m/").trim_start_matches("m");
+
+        if path.is_empty() {
+            return Ok(DerivationPath(Vec::new()));
+        }
+
+        let children = path
+            .split('/')
+            .map(ChildNumber::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(DerivationPath(children))
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "m")?;
+        for child in &self.0 {
+            write!(f, "/{}", child)?;
+        }
+        Ok(())
+    }
+}
+
+/// Derive a key using a full derivation path
+/// Ethereum standard: m/44'/60'/0'/0/0
+pub fn derive_path(master: &ExtendedPrivateKey, path: &str) -> Result<ExtendedPrivateKey, String> {
+    let path: DerivationPath = path.parse()?;
+    master.derive(&path)
+}
+
+/// Extended Public Key structure for Ethereum (watch-only, non-hardened derivation)
+#[derive(Debug, Clone)]
+pub struct ExtendedPublicKey {
+    pub public_key: [u8; 33],
+    pub chain_code: [u8; 32],
+    pub depth: u8,
+    pub parent_fingerprint: [u8; 4],
+    pub child_number: u32,
+}
+
+impl ExtendedPrivateKey {
+    /// Derive a descendant key by walking each step of a `DerivationPath`.
+    pub fn derive(&self, path: &DerivationPath) -> Result<Self, String> {
+        let mut current = self.clone();
+        for child in path.iter() {
+            current = derive_private_child(&current, child.to_raw())?;
+        }
+        Ok(current)
+    }
+
+    /// Derive the matching extended public key, for handing to a watch-only
+    /// signer that should never see the private key.
+    pub fn neuter(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey {
+            public_key: private_key_to_public_key(&self.private_key),
+            chain_code: self.chain_code,
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+        }
+    }
+
+    /// Encode as a standard 78-byte BIP32 payload plus checksum, Base58
+    /// (`xprv...`).
+    ///
+    /// Note: `parent_fingerprint` is `keccak256_fingerprint`, not BIP32's
+    /// HASH160(RIPEMD160(SHA256(pubkey))), so a key derived by this crate
+    /// only interoperates with other BIP32 tooling up to the point a parent
+    /// fingerprint is checked — most wallets surface it for display only,
+    /// but strict validators that recompute and compare it will reject it.
+    pub fn to_base58(&self, version: [u8; 4]) -> String {
+        let mut payload = Vec::with_capacity(78);
+        payload.extend_from_slice(&version);
+        payload.push(self.depth);
+        payload.extend_from_slice(&self.parent_fingerprint);
+        payload.extend_from_slice(&self.child_number.to_be_bytes());
+        payload.extend_from_slice(&self.chain_code);
+        payload.push(0x00);
+        payload.extend_from_slice(&self.private_key);
+
+        encode_base58check(&payload)
+    }
+
+    /// Decode a Base58Check-encoded `xprv` string.
+    pub fn from_base58(s: &str) -> Result<Self, String> {
+        let payload = decode_base58check(s)?;
+
+        if payload.len() != 78 {
+            return Err(format!(
+                "Invalid extended key payload length: {} (expected 78)",
+                payload.len()
+            ));
+        }
+
+        if payload[45] != 0x00 {
+            return Err("Not a private extended key (missing 0x00 prefix)".to_string());
+        }
+
+        let mut parent_fingerprint = [0u8; 4];
+        parent_fingerprint.copy_from_slice(&payload[5..9]);
+
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&payload[13..45]);
+
+        let mut private_key = [0u8; 32];
+        private_key.copy_from_slice(&payload[46..78]);
+
+        Ok(Self {
+            private_key,
+            chain_code,
+            depth: payload[4],
+            parent_fingerprint,
+            child_number: u32::from_be_bytes(payload[9..13].try_into().unwrap()),
+        })
+    }
+}
+
+impl ExtendedPublicKey {
+    /// Encode as a standard 78-byte BIP32 payload plus checksum, Base58
+    /// (`xpub...`).
+    pub fn to_base58(&self, version: [u8; 4]) -> String {
+        let mut payload = Vec::with_capacity(78);
+        payload.extend_from_slice(&version);
+        payload.push(self.depth);
+        payload.extend_from_slice(&self.parent_fingerprint);
+        payload.extend_from_slice(&self.child_number.to_be_bytes());
+        payload.extend_from_slice(&self.chain_code);
+        payload.extend_from_slice(&self.public_key);
+
+        encode_base58check(&payload)
+    }
+
+    /// Decode a Base58Check-encoded `xpub` string.
+    pub fn from_base58(s: &str) -> Result<Self, String> {
+        let payload = decode_base58check(s)?;
+
+        if payload.len() != 78 {
+            return Err(format!(
+                "Invalid extended key payload length: {} (expected 78)",
+                payload.len()
+            ));
+        }
+
+        let mut parent_fingerprint = [0u8; 4];
+        parent_fingerprint.copy_from_slice(&payload[5..9]);
+
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&payload[13..45]);
+
+        let mut public_key = [0u8; 33];
+        public_key.copy_from_slice(&payload[45..78]);
+
+        Ok(Self {
+            public_key,
+            chain_code,
+            depth: payload[4],
+            parent_fingerprint,
+            child_number: u32::from_be_bytes(payload[9..13].try_into().unwrap()),
+        })
+    }
+}
+
+/// Derive a non-hardened child extended public key (BIP32 public parent ->
+/// public child derivation). Hardened derivation is impossible from a
+/// public key, so `child_number` must be below the hardened offset.
+pub fn derive_public_child(
+    parent: &ExtendedPublicKey,
+    child_number: u32,
+) -> Result<ExtendedPublicKey, String> {
+    if child_number >= HARDENED_OFFSET {
+        return Err("Cannot derive a hardened child from a public key".to_string());
+    }
+
+    let mut mac =
+        HmacSha512::new_from_slice(&parent.chain_code).map_err(|e| format!("HMAC error: {}", e))?;
+    mac.update(&parent.public_key);
+    mac.update(&child_number.to_be_bytes());
+    let result = mac.finalize().into_bytes();
+
+    let mut il = [0u8; 32];
+    let mut chain_code = [0u8; 32];
+    il.copy_from_slice(&result[0..32]);
+    chain_code.copy_from_slice(&result[32..64]);
+
+    // Child public key: point(IL) + parent_point
+    let secp = Secp256k1::new();
+    let parent_point =
+        PublicKey::from_slice(&parent.public_key).map_err(|_| "Invalid parent public key")?;
+    let tweak = SecretKey::from_slice(&il).map_err(|_| "Invalid derived tweak")?;
+    let child_point = parent_point
+        .add_exp_tweak(&secp, &Scalar::from(tweak))
+        .map_err(|_| "Derived public key is the point at infinity")?;
+
+    let mut public_key = [0u8; 33];
+    public_key.copy_from_slice(&child_point.serialize());
+
+    let parent_fingerprint = keccak256_fingerprint(&parent.public_key);
+
+    Ok(ExtendedPublicKey {
+        public_key,
+        chain_code,
+        depth: parent.depth + 1,
+        parent_fingerprint,
+        child_number,
+    })
+}
+
+/// Derive a public-only key using a full non-hardened derivation path, e.g.
+/// `m/0/0` for fresh receive addresses under an account-level xpub.
+pub fn derive_public_path(
+    master: &ExtendedPublicKey,
+    path: &str,
+) -> Result<ExtendedPublicKey, String> {
+    let path: DerivationPath = path.parse()?;
+
+    let mut current = master.clone();
+
+    for child in path.iter() {
+        let index = match child {
+            ChildNumber::Normal { index } => *index,
+            ChildNumber::Hardened { .. } => {
+                return Err(format!(
+                    "Hardened segment `{}` cannot be derived from a public key",
+                    child
+                ));
+            }
         };
 
-        current = derive_private_child(&current, child_number)?;
+        current = derive_public_child(&current, index)?;
     }
 
     Ok(current)
@@ -172,3 +472,90 @@ pub fn display_private_key(key: &ExtendedPrivateKey, path: &str) {
     println!("Child Number:       {}", key.child_number);
     println!();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_master() -> ExtendedPrivateKey {
+        seed_to_master_key(&[0x5Au8; 32]).unwrap()
+    }
+
+    #[test]
+    fn xprv_base58_round_trip() {
+        let master = test_master();
+        let encoded = master.to_base58(VERSION_XPRV_MAINNET);
+        let decoded = ExtendedPrivateKey::from_base58(&encoded).unwrap();
+
+        assert_eq!(decoded.private_key, master.private_key);
+        assert_eq!(decoded.chain_code, master.chain_code);
+        assert_eq!(decoded.depth, master.depth);
+        assert_eq!(decoded.parent_fingerprint, master.parent_fingerprint);
+        assert_eq!(decoded.child_number, master.child_number);
+    }
+
+    #[test]
+    fn xpub_base58_round_trip() {
+        let master = test_master();
+        let public = master.neuter();
+        let encoded = public.to_base58(VERSION_XPUB_MAINNET);
+        let decoded = ExtendedPublicKey::from_base58(&encoded).unwrap();
+
+        assert_eq!(decoded.public_key, public.public_key);
+        assert_eq!(decoded.chain_code, public.chain_code);
+        assert_eq!(decoded.depth, public.depth);
+    }
+
+    #[test]
+    fn from_base58_rejects_corrupted_checksum() {
+        let master = test_master();
+        let mut encoded = master.to_base58(VERSION_XPRV_MAINNET);
+
+        // Flip the last character, which falls inside the checksum tail.
+        let mut chars: Vec<char> = encoded.chars().collect();
+        let last = chars.len() - 1;
+        chars[last] = if chars[last] == '1' { '2' } else { '1' };
+        encoded = chars.into_iter().collect();
+
+        assert!(ExtendedPrivateKey::from_base58(&encoded).is_err());
+    }
+
+    #[test]
+    fn derivation_path_display_round_trip() {
+        let path: DerivationPath = "m/44'/60'/0'/0/0".parse().unwrap();
+        assert_eq!(path.to_string(), "m/44'/60'/0'/0/0");
+    }
+
+    #[test]
+    fn child_number_accepts_h_suffix_as_alias_for_apostrophe() {
+        assert_eq!(
+            ChildNumber::from_str("60h").unwrap(),
+            ChildNumber::from_str("60'").unwrap()
+        );
+    }
+
+    #[test]
+    fn child_number_rejects_out_of_range_hardened_index() {
+        assert!(ChildNumber::from_str("2147483648'").is_err());
+    }
+
+    #[test]
+    fn derivation_path_rejects_trailing_slash() {
+        assert!("m/44'/60'/".parse::<DerivationPath>().is_err());
+    }
+
+    #[test]
+    fn derive_public_path_routes_through_typed_path() {
+        let master = test_master().neuter();
+        let derived = derive_public_path(&master, "m/0/1").unwrap();
+
+        let via_child = derive_public_child(&derive_public_child(&master, 0).unwrap(), 1).unwrap();
+        assert_eq!(derived.public_key, via_child.public_key);
+    }
+
+    #[test]
+    fn derive_public_path_rejects_hardened_segment() {
+        let master = test_master().neuter();
+        assert!(derive_public_path(&master, "m/0'").is_err());
+    }
+}
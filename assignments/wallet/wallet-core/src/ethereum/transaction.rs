@@ -3,6 +3,13 @@ use secp256k1::ecdsa::RecoverableSignature;
 use secp256k1::{Message, Secp256k1, SecretKey};
 use sha3::{Digest, Keccak256};
 
+/// Big-endian-encode a signature component for RLP, stripping leading zero
+/// bytes (RLP requires canonical minimal-length integers).
+fn trim_leading_zeros(bytes: &[u8]) -> Vec<u8> {
+    let first_nonzero = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    bytes[first_nonzero..].to_vec()
+}
+
 pub fn create_signed_transaction(
     private_key: &[u8; 32],
     to: &str,
@@ -43,8 +50,8 @@ pub fn create_signed_transaction(
     let (recovery_id, sig_bytes) = sig.serialize_compact();
 
     let v = recovery_id.to_i32() as u64 + chain_id * 2 + 35;
-    let r = sig_bytes[0..32].to_vec();
-    let s = sig_bytes[32..64].to_vec();
+    let r = trim_leading_zeros(&sig_bytes[0..32]);
+    let s = trim_leading_zeros(&sig_bytes[32..64]);
 
     // --- Signed tx ---
     let mut signed = RlpStream::new_list(9);
@@ -60,3 +67,76 @@ pub fn create_signed_transaction(
 
     Ok(format!("0x{}", hex::encode(signed.out())))
 }
+
+/// Build and sign an EIP-1559 (type-2) transaction.
+///
+/// The signing payload is `rlp([chain_id, nonce, max_priority_fee_per_gas,
+/// max_fee_per_gas, gas_limit, to, value, data, access_list])` and the
+/// signing hash is `keccak256(0x02 || payload)`. The final raw transaction
+/// prepends the same type byte to the signed RLP list.
+pub fn create_signed_transaction_1559(
+    private_key: &[u8; 32],
+    to: &str,
+    value_wei: u128,
+    nonce: u64,
+    max_priority_fee_per_gas: u128,
+    max_fee_per_gas: u128,
+    gas_limit: u64,
+    chain_id: u64,
+) -> Result<String, String> {
+    let to_bytes = hex::decode(to.trim_start_matches("0x")).map_err(|_| "Invalid to address")?;
+
+    if to_bytes.len() != 20 {
+        return Err("To address must be 20 bytes".to_string());
+    }
+
+    // --- Unsigned payload (EIP-1559) ---
+    let mut stream = RlpStream::new_list(9);
+    stream.append(&chain_id);
+    stream.append(&nonce);
+    stream.append(&max_priority_fee_per_gas);
+    stream.append(&max_fee_per_gas);
+    stream.append(&gas_limit);
+    stream.append(&to_bytes);
+    stream.append(&value_wei);
+    stream.append_empty_data();
+    stream.begin_list(0); // empty access_list
+
+    let mut payload = vec![0x02u8];
+    payload.extend_from_slice(&stream.out());
+    let hash = Keccak256::digest(&payload);
+
+    // --- Sign ---
+    let secp = Secp256k1::new();
+    let secret_key = SecretKey::from_slice(private_key).map_err(|_| "Invalid private key")?;
+
+    let message = Message::from_digest_slice(&hash).map_err(|_| "Invalid message")?;
+
+    let sig: RecoverableSignature = secp.sign_ecdsa_recoverable(&message, &secret_key);
+
+    let (recovery_id, sig_bytes) = sig.serialize_compact();
+
+    let y_parity = recovery_id.to_i32() as u64;
+    let r = trim_leading_zeros(&sig_bytes[0..32]);
+    let s = trim_leading_zeros(&sig_bytes[32..64]);
+
+    // --- Signed tx ---
+    let mut signed = RlpStream::new_list(12);
+    signed.append(&chain_id);
+    signed.append(&nonce);
+    signed.append(&max_priority_fee_per_gas);
+    signed.append(&max_fee_per_gas);
+    signed.append(&gas_limit);
+    signed.append(&to_bytes);
+    signed.append(&value_wei);
+    signed.append_empty_data();
+    signed.begin_list(0); // empty access_list
+    signed.append(&y_parity);
+    signed.append(&r);
+    signed.append(&s);
+
+    let mut raw = vec![0x02u8];
+    raw.extend_from_slice(&signed.out());
+
+    Ok(format!("0x{}", hex::encode(raw)))
+}
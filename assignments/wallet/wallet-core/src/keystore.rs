@@ -0,0 +1,180 @@
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use pbkdf2::pbkdf2_hmac;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+use uuid::Uuid;
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+const KDF_ITERATIONS: u32 = 262_144;
+const DK_LEN: usize = 32;
+
+/// Ethereum Web3 Secret Storage (V3) keystore
+#[derive(Debug, Serialize, Deserialize)]
+pub struct KeystoreV3 {
+    pub version: u8,
+    pub id: String,
+    pub crypto: CryptoParams,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CryptoParams {
+    pub cipher: String,
+    pub ciphertext: String,
+    pub cipherparams: CipherParams,
+    pub kdf: String,
+    pub kdfparams: KdfParams,
+    pub mac: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CipherParams {
+    pub iv: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct KdfParams {
+    pub dklen: usize,
+    pub salt: String,
+    pub c: u32,
+    pub prf: String,
+}
+
+/// Encrypt a 32-byte private key into a V3 keystore using the given password.
+///
+/// Derives a symmetric key with PBKDF2-HMAC-SHA256 over a random salt,
+/// encrypts with AES-128-CTR under a random IV, and authenticates with
+/// `keccak256(derived_key[16..32] || ciphertext)`.
+pub fn encrypt(private_key: &[u8; 32], password: &str) -> KeystoreV3 {
+    let mut salt = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut salt);
+
+    let mut derived_key = [0u8; DK_LEN];
+    pbkdf2_hmac::<Sha256>(password.as_bytes(), &salt, KDF_ITERATIONS, &mut derived_key);
+
+    let mut iv = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut iv);
+
+    let encryption_key = &derived_key[0..16];
+    let mac_key = &derived_key[16..32];
+
+    let mut ciphertext = *private_key;
+    let mut cipher = Aes128Ctr::new(encryption_key.into(), (&iv).into());
+    cipher.apply_keystream(&mut ciphertext);
+
+    let mut mac_input = mac_key.to_vec();
+    mac_input.extend_from_slice(&ciphertext);
+    let mac = Keccak256::digest(&mac_input);
+
+    KeystoreV3 {
+        version: 3,
+        id: Uuid::new_v4().to_string(),
+        crypto: CryptoParams {
+            cipher: "aes-128-ctr".to_string(),
+            ciphertext: hex::encode(ciphertext),
+            cipherparams: CipherParams {
+                iv: hex::encode(iv),
+            },
+            kdf: "pbkdf2".to_string(),
+            kdfparams: KdfParams {
+                dklen: DK_LEN,
+                salt: hex::encode(salt),
+                c: KDF_ITERATIONS,
+                prf: "hmac-sha256".to_string(),
+            },
+            mac: hex::encode(mac),
+        },
+    }
+}
+
+/// Decrypt a V3 keystore back into its 32-byte private key.
+///
+/// Re-derives the symmetric key and verifies the MAC in constant time
+/// before decrypting, so a wrong password fails with a single clear error
+/// instead of silently returning garbage key material.
+pub fn decrypt(keystore: &KeystoreV3, password: &str) -> Result<[u8; 32], String> {
+    if keystore.crypto.kdf != "pbkdf2" {
+        return Err(format!("Unsupported KDF: {}", keystore.crypto.kdf));
+    }
+    if keystore.crypto.cipher != "aes-128-ctr" {
+        return Err(format!("Unsupported cipher: {}", keystore.crypto.cipher));
+    }
+
+    let salt = hex::decode(&keystore.crypto.kdfparams.salt).map_err(|_| "Invalid salt")?;
+    let iv = hex::decode(&keystore.crypto.cipherparams.iv).map_err(|_| "Invalid IV")?;
+    let ciphertext =
+        hex::decode(&keystore.crypto.ciphertext).map_err(|_| "Invalid ciphertext")?;
+    let expected_mac = hex::decode(&keystore.crypto.mac).map_err(|_| "Invalid mac")?;
+
+    let mut derived_key = vec![0u8; keystore.crypto.kdfparams.dklen];
+    pbkdf2_hmac::<Sha256>(
+        password.as_bytes(),
+        &salt,
+        keystore.crypto.kdfparams.c,
+        &mut derived_key,
+    );
+
+    if derived_key.len() < 32 {
+        return Err("Derived key too short for AES-128-CTR + MAC".to_string());
+    }
+
+    let encryption_key = &derived_key[0..16];
+    let mac_key = &derived_key[16..32];
+
+    let mut mac_input = mac_key.to_vec();
+    mac_input.extend_from_slice(&ciphertext);
+    let computed_mac = Keccak256::digest(&mac_input);
+
+    if !constant_time_eq(&computed_mac, &expected_mac) {
+        return Err("MAC mismatch: wrong password or corrupted keystore".to_string());
+    }
+
+    if ciphertext.len() != 32 || iv.len() != 16 {
+        return Err("Ciphertext/IV have unexpected length for a 32-byte private key".to_string());
+    }
+
+    let mut private_key_bytes = ciphertext;
+    let mut cipher = Aes128Ctr::new(encryption_key.into(), iv.as_slice().into());
+    cipher.apply_keystream(&mut private_key_bytes);
+
+    let mut private_key = [0u8; 32];
+    private_key.copy_from_slice(&private_key_bytes);
+    Ok(private_key)
+}
+
+/// Constant-time byte slice comparison, to avoid leaking MAC-match timing.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+    diff == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_with_correct_password() {
+        let private_key = [0x42u8; 32];
+        let keystore = encrypt(&private_key, "correct horse battery staple");
+
+        assert_eq!(decrypt(&keystore, "correct horse battery staple").unwrap(), private_key);
+    }
+
+    #[test]
+    fn wrong_password_fails_mac_check() {
+        let private_key = [0x42u8; 32];
+        let keystore = encrypt(&private_key, "correct horse battery staple");
+
+        let err = decrypt(&keystore, "wrong password").unwrap_err();
+        assert!(err.contains("MAC mismatch"), "unexpected error: {}", err);
+    }
+}
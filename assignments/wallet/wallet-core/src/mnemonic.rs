@@ -1,4 +1,6 @@
+use crate::wordlist::get_wordlist;
 use sha2::{Digest, Sha256};
+use std::fmt;
 
 pub fn entropy_to_mnemonic(entropy_bytes: &[u8], word_list: &[String]) -> Result<String, String> {
     //Validate entropy length
@@ -62,6 +64,80 @@ pub fn entropy_to_mnemonic(entropy_bytes: &[u8], word_list: &[String]) -> Result
     Ok(mnemonic)
 }
 
+const VALID_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MnemonicError {
+    InvalidWordCount(usize),
+    UnknownWord(String),
+    ChecksumMismatch,
+}
+
+impl fmt::Display for MnemonicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MnemonicError::InvalidWordCount(n) => write!(
+                f,
+                "Invalid word count: {} (must be 12, 15, 18, 21, or 24)",
+                n
+            ),
+            MnemonicError::UnknownWord(word) => write!(f, "Unknown word in mnemonic: {}", word),
+            MnemonicError::ChecksumMismatch => write!(f, "Mnemonic checksum mismatch"),
+        }
+    }
+}
+
+impl std::error::Error for MnemonicError {}
+
+/// Validate a mnemonic phrase: word count, every word known, and checksum.
+pub fn validate_mnemonic(phrase: &str) -> Result<(), MnemonicError> {
+    mnemonic_to_entropy(phrase).map(|_| ())
+}
+
+/// Recover the original entropy bytes from a mnemonic phrase, verifying the
+/// checksum along the way.
+pub fn mnemonic_to_entropy(phrase: &str) -> Result<Vec<u8>, MnemonicError> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+
+    if !VALID_WORD_COUNTS.contains(&words.len()) {
+        return Err(MnemonicError::InvalidWordCount(words.len()));
+    }
+
+    let word_list = get_wordlist();
+    let mut bits = String::with_capacity(words.len() * 11);
+
+    for word in &words {
+        let index = word_list
+            .iter()
+            .position(|candidate| candidate == word)
+            .ok_or_else(|| MnemonicError::UnknownWord(word.to_string()))?;
+        bits.push_str(&format!("{:011b}", index));
+    }
+
+    let total_bits = bits.len();
+    let checksum_bits_len = total_bits / 33;
+    let entropy_bits_len = total_bits - checksum_bits_len;
+
+    let entropy_bits = &bits[0..entropy_bits_len];
+    let checksum_bits = &bits[entropy_bits_len..];
+
+    let entropy: Vec<u8> = entropy_bits
+        .as_bytes()
+        .chunks(8)
+        .map(|chunk| u8::from_str_radix(std::str::from_utf8(chunk).unwrap(), 2).unwrap())
+        .collect();
+
+    let hash = Sha256::digest(&entropy);
+    let hash_bits: String = hash.iter().map(|byte| format!("{:08b}", byte)).collect();
+    let expected_checksum = &hash_bits[0..checksum_bits_len];
+
+    if checksum_bits != expected_checksum {
+        return Err(MnemonicError::ChecksumMismatch);
+    }
+
+    Ok(entropy)
+}
+
 pub fn load_wordlist(path: &str) -> Result<Vec<String>, std::io::Error> {
     let content = std::fs::read_to_string(path)?;
     let words: Vec<String> = content
@@ -83,4 +159,32 @@ mod tests {
         assert_eq!(entropy_16.len() / 4, 4);
         assert_eq!(entropy_32.len() / 4, 8);
     }
+
+    #[test]
+    fn test_validate_mnemonic_wrong_word_count() {
+        let phrase = "abandon abandon abandon";
+        assert_eq!(
+            validate_mnemonic(phrase),
+            Err(MnemonicError::InvalidWordCount(3))
+        );
+    }
+
+    #[test]
+    fn test_validate_mnemonic_unknown_word() {
+        let phrase = "abandon ".repeat(11) + "notarealbip39word";
+        assert_eq!(
+            validate_mnemonic(&phrase),
+            Err(MnemonicError::UnknownWord("notarealbip39word".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_round_trip_all_zero_entropy() {
+        // Standard BIP39 test vector for 16 zero bytes of entropy.
+        let phrase =
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        assert_eq!(validate_mnemonic(phrase), Ok(()));
+        assert_eq!(mnemonic_to_entropy(phrase).unwrap(), vec![0u8; 16]);
+    }
 }